@@ -0,0 +1,133 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::ops::{Generator, GeneratorState};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+/// The handle an `async` block built by `mk_gen!` uses to yield a value.
+pub struct Yielder<Item> {
+    slot: Rc<Cell<Option<Item>>>,
+}
+
+impl<Item> Yielder<Item> {
+    #[inline]
+    pub fn new(slot: Rc<Cell<Option<Item>>>) -> Self {
+        Self { slot }
+    }
+
+    #[inline]
+    pub fn yield_(&self, item: Item) -> impl Future<Output = ()> {
+        self.slot.set(Some(item));
+        YieldOnce(false)
+    }
+}
+
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            Poll::Pending
+        }
+    }
+}
+
+/// A generator built out of an `async` block instead of `yield` syntax.
+/// It implements the unstable `Generator` trait, so it still needs a
+/// nightly compiler.
+pub struct GeneratorFn<Item, F> {
+    slot: Rc<Cell<Option<Item>>>,
+    future: Pin<Box<F>>,
+}
+
+impl<Item, F> GeneratorFn<Item, F>
+where
+    F: Future<Output = ()>,
+{
+    #[inline]
+    pub fn new(slot: Rc<Cell<Option<Item>>>, future: F) -> Self {
+        Self {
+            slot,
+            future: Box::pin(future),
+        }
+    }
+}
+
+impl<Item, F> Generator for GeneratorFn<Item, F>
+where
+    F: Future<Output = ()>,
+{
+    type Yield = Item;
+    type Return = ();
+
+    #[inline]
+    unsafe fn resume(&mut self, _arg: ()) -> GeneratorState<Self::Yield, Self::Return> {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        match self.future.as_mut().poll(&mut cx) {
+            Poll::Pending => {
+                let item = self
+                    .slot
+                    .take()
+                    .expect("GeneratorFn future yielded control without calling Yielder::yield_");
+                GeneratorState::Yielded(item)
+            }
+            Poll::Ready(()) => GeneratorState::Complete(()),
+        }
+    }
+}
+
+/// Builds a `GeneratorFn` from `$body`, a closure that takes a `Yielder`
+/// and returns the `async` block using it.
+#[macro_export]
+macro_rules! mk_gen {
+    (let $name:ident = $body:expr) => {
+        let slot = ::std::rc::Rc::new(::std::cell::Cell::new(None));
+        let yielder = $crate::genfn::Yielder::new(::std::rc::Rc::clone(&slot));
+        #[allow(unused_mut)]
+        let mut $name = $crate::genfn::GeneratorFn::new(slot, ($body)(yielder));
+    };
+}
+
+fn noop_waker() -> Waker {
+    unsafe fn clone(_: *const ()) -> RawWaker {
+        raw_waker()
+    }
+    unsafe fn no_op(_: *const ()) {}
+
+    fn raw_waker() -> RawWaker {
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    unsafe { Waker::from_raw(raw_waker()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gentrait::GenTrait;
+
+    #[test]
+    fn mk_gen_yields_and_completes() {
+        mk_gen!(let gen = |yielder: Yielder<u32>| async move {
+            yielder.yield_(1).await;
+            yielder.yield_(2).await;
+        });
+
+        let mut gen = gen.fuse();
+        let mut iter = gen.iter();
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -1,4 +1,5 @@
-use std::ops::{Generator, GeneratorState};
+use std::ops::{DerefMut, Generator, GeneratorState};
+use std::pin::Pin;
 
 pub struct GenIter<G>(Option<G>);
 
@@ -26,7 +27,7 @@ where
         let mut gen = self.take()?;
 
         unsafe {
-            match gen.resume() {
+            match gen.resume(()) {
                 GeneratorState::Yielded(y) => {
                     self.0 = Some(gen);
                     Some(y)
@@ -36,3 +37,149 @@ where
         }
     }
 }
+
+/// A heap-allocated, pinned generator, for generators that can't be `Unpin`.
+pub type BoxGenerator<Y, R = ()> = Pin<Box<dyn Generator<Yield = Y, Return = R>>>;
+
+impl<Y, R> Iterator for Pin<Box<dyn Generator<Yield = Y, Return = R>>> {
+    type Item = Y;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `resume` takes `&mut self`, not a pinned receiver; the
+        // `Box` still guarantees the generator itself is never moved.
+        let gen = unsafe { self.as_mut().get_unchecked_mut() };
+
+        match unsafe { gen.resume(()) } {
+            GeneratorState::Yielded(y) => Some(y),
+            GeneratorState::Complete(_) => None,
+        }
+    }
+}
+
+impl<'a, G> Iterator for Pin<&'a mut GenIter<G>>
+where
+    G: Generator,
+{
+    type Item = G::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: the wrapped generator is only ever reached through this
+        // pinned reference, so it is never moved once pinned here.
+        let gen = match unsafe { self.as_mut().get_unchecked_mut() }.0.as_mut() {
+            Some(gen) => gen,
+            None => return None,
+        };
+
+        match unsafe { gen.resume(()) } {
+            GeneratorState::Yielded(y) => Some(y),
+            GeneratorState::Complete(_) => {
+                unsafe { self.as_mut().get_unchecked_mut() }.0 = None;
+                None
+            }
+        }
+    }
+}
+
+/// The iterator a pinned generator handle turns into via `IntoIterator`.
+pub struct Iter<P>(Pin<P>);
+
+impl<P> Iterator for Iter<P>
+where
+    P: DerefMut,
+    P::Target: Generator,
+{
+    type Item = <P::Target as Generator>::Yield;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `resume` takes `&mut self`, not a pinned receiver; `self.0`
+        // guarantees `P::Target` is never moved out from under the pin.
+        let gen = unsafe { self.0.as_mut().get_unchecked_mut() };
+
+        match unsafe { gen.resume(()) } {
+            GeneratorState::Yielded(y) => Some(y),
+            GeneratorState::Complete(_) => None,
+        }
+    }
+}
+
+impl<'a, G> IntoIterator for Pin<&'a mut G>
+where
+    G: Generator,
+{
+    type Item = G::Yield;
+    type IntoIter = Iter<&'a mut G>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Iter(self)
+    }
+}
+
+impl<G> IntoIterator for Pin<Box<G>>
+where
+    G: Generator,
+{
+    type Item = G::Yield;
+    type IntoIter = Iter<Box<G>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Iter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_generator() {
+        let mut gen: BoxGenerator<u32, ()> = Box::pin(move || {
+            yield 1;
+            yield 2;
+        });
+
+        assert_eq!(gen.next(), Some(1));
+        assert_eq!(gen.next(), Some(2));
+        assert_eq!(gen.next(), None);
+    }
+
+    #[test]
+    fn pinned_geniter() {
+        let mut geniter = GenIter::new(move || {
+            yield 1u32;
+            yield 2;
+        });
+        let mut geniter = unsafe { Pin::new_unchecked(&mut geniter) };
+
+        assert_eq!(geniter.as_mut().next(), Some(1));
+        assert_eq!(geniter.as_mut().next(), Some(2));
+        assert_eq!(geniter.as_mut().next(), None);
+    }
+
+    #[test]
+    fn into_iter_pinned_ref() {
+        let mut gen = move || {
+            yield 1u32;
+            yield 2;
+        };
+        let pinned = unsafe { Pin::new_unchecked(&mut gen) };
+
+        let collected: Vec<u32> = pinned.into_iter().collect();
+        assert_eq!(collected, [1, 2]);
+    }
+
+    #[test]
+    fn into_iter_pinned_box() {
+        let gen = Box::pin(move || {
+            yield 1u32;
+            yield 2;
+        });
+
+        let collected: Vec<u32> = gen.into_iter().collect();
+        assert_eq!(collected, [1, 2]);
+    }
+}
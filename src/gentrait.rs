@@ -3,13 +3,14 @@ use std::marker::Unpin;
 use std::ops::{Generator, GeneratorState};
 use std::pin::PinMut;
 
-pub trait GenTrait {
+/// `R` is the type of the value passed in at each resume point.
+pub trait GenTrait<R = ()> {
     type Yielding;
     type Returning;
 
-    fn next(PinMut<Self>) -> Option<Self::Yielding>;
+    fn next(PinMut<Self>, arg: R) -> Option<Self::Yielding>;
 
-    unsafe fn resume(PinMut<Self>) -> GeneratorState<Self::Yielding, Self::Returning>;
+    unsafe fn resume(PinMut<Self>, arg: R) -> GeneratorState<Self::Yielding, Self::Returning>;
 
     #[inline]
     fn map<U, F>(self, f: F) -> Map<Self, F>
@@ -29,6 +30,32 @@ pub trait GenTrait {
         Filter::new(self, f)
     }
 
+    #[inline]
+    fn map_return<U, F>(self, f: F) -> MapReturn<Self, F>
+    where
+        Self: Sized,
+        F: FnOnce(Self::Returning) -> U,
+    {
+        MapReturn::new(self, f)
+    }
+
+    #[inline]
+    fn map_resume<F, R2>(self, f: F) -> MapResume<Self, F>
+    where
+        Self: Sized,
+        F: Fn(R2) -> R,
+    {
+        MapResume::new(self, f)
+    }
+
+    #[inline]
+    fn fuse(self) -> Fuse<Self>
+    where
+        Self: Sized,
+    {
+        Fuse::new(self)
+    }
+
     #[inline]
     fn iter(&mut self) -> Iter<Self>
     where
@@ -38,24 +65,24 @@ pub trait GenTrait {
     }
 }
 
-impl<G> GenTrait for G
+impl<G, R> GenTrait<R> for G
 where
-    G: Generator + Unpin,
+    G: Generator<R> + Unpin,
 {
     type Yielding = G::Yield;
     type Returning = G::Return;
 
     #[inline]
-    fn next(mut ptr: PinMut<Self>) -> Option<Self::Yielding> {
-        match unsafe { PinMut::get_mut(ptr.reborrow()).resume() } {
+    fn next(mut ptr: PinMut<Self>, arg: R) -> Option<Self::Yielding> {
+        match unsafe { PinMut::get_mut(ptr.reborrow()).resume(arg) } {
             GeneratorState::Yielded(y) => Some(y),
             GeneratorState::Complete(_) => None,
         }
     }
 
     #[inline]
-    unsafe fn resume(mut ptr: PinMut<Self>) -> GeneratorState<Self::Yielding, Self::Returning> {
-        <Self as Generator>::resume(PinMut::get_mut(ptr.reborrow()))
+    unsafe fn resume(mut ptr: PinMut<Self>, arg: R) -> GeneratorState<Self::Yielding, Self::Returning> {
+        <Self as Generator<R>>::resume(PinMut::get_mut(ptr.reborrow()), arg)
     }
 }
 
@@ -87,17 +114,17 @@ impl <F: Unpin, G: Unpin> AsPin<Self> for Map<G, F> {
     }
 }
 
-impl<U, G, F> Generator for Map<G, F>
+impl<U, G, F, R> Generator<R> for Map<G, F>
 where
-    G: Generator,
+    G: Generator<R>,
     F: Fn(G::Yield) -> U,
 {
     type Yield = U;
     type Return = G::Return;
 
     #[inline]
-    unsafe fn resume(&mut self) -> GeneratorState<Self::Yield, Self::Return> {
-        match self.generator.resume() {
+    unsafe fn resume(&mut self, arg: R) -> GeneratorState<Self::Yield, Self::Return> {
+        match self.generator.resume(arg) {
             GeneratorState::Yielded(y) => GeneratorState::Yielded((self.func)(y)),
             GeneratorState::Complete(r) => GeneratorState::Complete(r),
         }
@@ -132,22 +159,25 @@ impl <F: Unpin, G: Unpin> AsPin<Self> for Filter<G, F> {
     }
 }
 
-impl<G, F> Generator for Filter<G, F>
+impl<G, F, R> Generator<R> for Filter<G, F>
 where
-    G: Generator,
+    G: Generator<R>,
     F: Fn(&G::Yield) -> bool,
+    R: Default,
 {
     type Yield = G::Yield;
     type Return = G::Return;
 
     #[inline]
-    unsafe fn resume(&mut self) -> GeneratorState<Self::Yield, Self::Return> {
+    unsafe fn resume(&mut self, arg: R) -> GeneratorState<Self::Yield, Self::Return> {
+        let mut arg = arg;
         loop {
-            match self.generator.resume() {
+            match self.generator.resume(arg) {
                 GeneratorState::Yielded(y) => {
                     if (self.pred)(&y) {
                         break GeneratorState::Yielded(y);
                     }
+                    arg = R::default();
                     continue;
                 }
                 GeneratorState::Complete(r) => break GeneratorState::Complete(r),
@@ -156,6 +186,149 @@ where
     }
 }
 
+pub struct MapReturn<G, F> {
+    generator: G,
+    func: Option<F>,
+}
+
+impl<G, F> MapReturn<G, F> {
+
+    #[inline]
+    pub fn new(generator: G, func: F) -> Self {
+        Self { generator, func: Some(func) }
+    }
+}
+
+impl<F, G: Unpin> AsPin<G> for MapReturn<G, F> {
+
+    #[inline]
+    fn as_pin(&mut self) -> PinMut<G> {
+        PinMut::new(&mut self.generator)
+    }
+}
+
+impl <F: Unpin, G: Unpin> AsPin<Self> for MapReturn<G, F> {
+    #[inline]
+    fn as_pin(&mut self) -> PinMut<Self> {
+        PinMut::new(self)
+    }
+}
+
+impl<U, G, F, R> Generator<R> for MapReturn<G, F>
+where
+    G: Generator<R>,
+    F: FnOnce(G::Return) -> U,
+{
+    type Yield = G::Yield;
+    type Return = U;
+
+    #[inline]
+    unsafe fn resume(&mut self, arg: R) -> GeneratorState<Self::Yield, Self::Return> {
+        match self.generator.resume(arg) {
+            GeneratorState::Yielded(y) => GeneratorState::Yielded(y),
+            GeneratorState::Complete(r) => {
+                let func = self.func.take().expect("generator resumed after completion");
+                GeneratorState::Complete(func(r))
+            }
+        }
+    }
+}
+
+pub struct MapResume<G, F> {
+    generator: G,
+    func: F,
+}
+
+impl<G, F> MapResume<G, F> {
+
+    #[inline]
+    pub fn new(generator: G, func: F) -> Self {
+        Self { generator, func }
+    }
+}
+
+impl<F, G: Unpin> AsPin<G> for MapResume<G, F> {
+
+    #[inline]
+    fn as_pin(&mut self) -> PinMut<G> {
+        PinMut::new(&mut self.generator)
+    }
+}
+
+impl <F: Unpin, G: Unpin> AsPin<Self> for MapResume<G, F> {
+    #[inline]
+    fn as_pin(&mut self) -> PinMut<Self> {
+        PinMut::new(self)
+    }
+}
+
+impl<G, F, R, R2> Generator<R> for MapResume<G, F>
+where
+    G: Generator<R2>,
+    F: Fn(R) -> R2,
+{
+    type Yield = G::Yield;
+    type Return = G::Return;
+
+    #[inline]
+    unsafe fn resume(&mut self, arg: R) -> GeneratorState<Self::Yield, Self::Return> {
+        self.generator.resume((self.func)(arg))
+    }
+}
+
+/// Guards a generator against being resumed again once it has completed.
+pub struct Fuse<G> {
+    generator: G,
+    done: bool,
+}
+
+impl<G> Fuse<G> {
+
+    #[inline]
+    pub fn new(generator: G) -> Self {
+        Self { generator, done: false }
+    }
+}
+
+impl<G: Unpin> AsPin<G> for Fuse<G> {
+
+    #[inline]
+    fn as_pin(&mut self) -> PinMut<G> {
+        PinMut::new(&mut self.generator)
+    }
+}
+
+impl <G: Unpin> AsPin<Self> for Fuse<G> {
+    #[inline]
+    fn as_pin(&mut self) -> PinMut<Self> {
+        PinMut::new(self)
+    }
+}
+
+impl<G, R> Generator<R> for Fuse<G>
+where
+    G: Generator<R>,
+    G::Return: Default,
+{
+    type Yield = G::Yield;
+    type Return = G::Return;
+
+    #[inline]
+    unsafe fn resume(&mut self, arg: R) -> GeneratorState<Self::Yield, Self::Return> {
+        if self.done {
+            return GeneratorState::Complete(Default::default());
+        }
+
+        match self.generator.resume(arg) {
+            GeneratorState::Yielded(y) => GeneratorState::Yielded(y),
+            GeneratorState::Complete(r) => {
+                self.done = true;
+                GeneratorState::Complete(r)
+            }
+        }
+    }
+}
+
 /// An Iterator that wraps over a Generator.
 /// Ensures that generator's resume never gets called once the generator completed.
 pub struct Iter<'a, G: 'a>(Option<PinMut<'a, G>>);
@@ -177,13 +350,39 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let mut pin = self.0.take()?;
 
-        GenTrait::next(pin.reborrow()).map(move |item| {
+        GenTrait::next(pin.reborrow(), ()).map(move |item| {
             self.0 = Some(pin);
             item
         })
     }
 }
 
+/// Wraps a generator as a two-way channel: `send` resumes it with a value
+/// and hands back whatever it yields next.
+pub struct Coroutine<G> {
+    generator: G,
+}
+
+impl<G> Coroutine<G> {
+    #[inline]
+    pub fn new(generator: G) -> Self {
+        Self { generator }
+    }
+}
+
+impl<G, R> Coroutine<G>
+where
+    G: GenTrait<R> + Unpin,
+{
+    #[inline]
+    pub fn send(&mut self, arg: R) -> Option<G::Yielding> {
+        match unsafe { GenTrait::resume(PinMut::new(&mut self.generator), arg) } {
+            GeneratorState::Yielded(y) => Some(y),
+            GeneratorState::Complete(_) => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,4 +401,72 @@ mod tests {
         assert_eq!(iter.next(), Some(300));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn coroutine_send() {
+        let mut echo = Coroutine::new(|| {
+            let mut received = 0u32;
+            loop {
+                received = yield received;
+            }
+        });
+
+        assert_eq!(echo.send(1), Some(0));
+        assert_eq!(echo.send(2), Some(2));
+        assert_eq!(echo.send(3), Some(3));
+    }
+
+    #[test]
+    fn map_resume() {
+        let mut echo = Coroutine::new((move || {
+            let mut received = 0u32;
+            loop {
+                received = yield received;
+            }
+        }).map_resume(|x: u32| x + 1));
+
+        assert_eq!(echo.send(0), Some(0));
+        assert_eq!(echo.send(1), Some(2));
+    }
+
+    #[test]
+    fn map_return() {
+        let mut gen = (move || {
+            yield 1u32;
+            2u32
+        }).map_return(|r| r * 100);
+
+        match unsafe { GenTrait::resume(PinMut::new(&mut gen), ()) } {
+            GeneratorState::Yielded(y) => assert_eq!(y, 1),
+            GeneratorState::Complete(_) => panic!("expected a yield"),
+        }
+
+        match unsafe { GenTrait::resume(PinMut::new(&mut gen), ()) } {
+            GeneratorState::Yielded(_) => panic!("expected completion"),
+            GeneratorState::Complete(r) => assert_eq!(r, 200),
+        }
+    }
+
+    #[test]
+    fn fuse() {
+        let mut gen = (move || {
+            yield 1u32;
+        }).fuse();
+
+        match unsafe { GenTrait::resume(PinMut::new(&mut gen), ()) } {
+            GeneratorState::Yielded(y) => assert_eq!(y, 1),
+            GeneratorState::Complete(_) => panic!("expected a yield"),
+        }
+
+        match unsafe { GenTrait::resume(PinMut::new(&mut gen), ()) } {
+            GeneratorState::Yielded(_) => panic!("expected completion"),
+            GeneratorState::Complete(()) => {}
+        }
+
+        // Resuming again must not touch the wrapped generator.
+        match unsafe { GenTrait::resume(PinMut::new(&mut gen), ()) } {
+            GeneratorState::Yielded(_) => panic!("fused generator yielded after completion"),
+            GeneratorState::Complete(()) => {}
+        }
+    }
 }
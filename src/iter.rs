@@ -13,7 +13,7 @@ macro_rules! yield_from {
     ($gen:expr) => {
         loop {
             unsafe {
-                match $gen.resume() {
+                match $gen.resume(()) {
                     GeneratorState::Yielded(y) => yield y,
                     GeneratorState::Complete(_) => break,
                 }